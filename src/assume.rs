@@ -1,4 +1,6 @@
 use core::hint::unreachable_unchecked;
+use core::mem::align_of;
+use core::ptr::NonNull;
 
 /// This has no effect if `predicate` returns true and invokes undefined behavior or panics in debug otherwise. This provides compiler with
 /// additional opportunities for optimization when used in the critical paths of your application. Actual effect must
@@ -67,4 +69,77 @@ impl <T> OptionAssume<T> for Option<T> {
             None => ()
         }
     }
+}
+
+/// Tells the optimizer that `ptr` is aligned to `align_of::<T>()`, invoking undefined behavior or
+/// panicking in debug otherwise.
+/// # Safety
+/// `ptr as usize % align_of::<T>()` must be `0`.
+#[inline(always)]
+pub unsafe fn assume_aligned<T>(ptr: *const T) -> *const T {
+    let f = (ptr as usize).is_multiple_of(align_of::<T>());
+    debug_assert!(f);
+    if !f {
+        unsafe {
+            unreachable_unchecked();
+        }
+    }
+    ptr
+}
+
+/// Mutable-pointer counterpart of `assume_aligned`.
+/// # Safety
+/// `ptr as usize % align_of::<T>()` must be `0`.
+#[inline(always)]
+pub unsafe fn assume_aligned_mut<T>(ptr: *mut T) -> *mut T {
+    unsafe {
+        assume_aligned(ptr as *const T) as *mut T
+    }
+}
+
+/// Tells the optimizer that `ptr` is not null, invoking undefined behavior or panicking in debug
+/// otherwise.
+/// # Safety
+/// `ptr` must not be null.
+#[inline(always)]
+pub unsafe fn assume_nonnull<T>(ptr: *mut T) -> NonNull<T> {
+    let f = !ptr.is_null();
+    debug_assert!(f);
+    if !f {
+        unsafe {
+            unreachable_unchecked();
+        }
+    }
+    unsafe {
+        NonNull::new_unchecked(ptr)
+    }
+}
+
+pub trait ResultAssume<T, E> {
+    /// Unwraps `self` if it contains `Ok` and invokes undefined behavior or panics in debug otherwise.
+    /// # Safety
+    /// `self` must be `Ok`.
+    unsafe fn assume_ok(self) -> T;
+    /// Unwraps `self` if it contains `Err` and invokes undefined behavior or panics in debug otherwise.
+    /// # Safety
+    /// `self` must be `Err`.
+    unsafe fn assume_err(self) -> E;
+}
+
+impl <T, E> ResultAssume<T, E> for Result<T, E> {
+    unsafe fn assume_ok(self) -> T {
+        debug_assert!(self.is_ok());
+        match self {
+            Ok(this) => this,
+            Err(_) => unreachable_unchecked()
+        }
+    }
+
+    unsafe fn assume_err(self) -> E {
+        debug_assert!(self.is_err());
+        match self {
+            Err(this) => this,
+            Ok(_) => unreachable_unchecked()
+        }
+    }
 }
\ No newline at end of file
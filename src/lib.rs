@@ -5,3 +5,4 @@
 pub mod shared_box;
 pub mod assume;
 pub mod pointers;
+pub mod pack;
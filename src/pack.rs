@@ -0,0 +1,141 @@
+use core::mem::{align_of, size_of};
+use core::ptr;
+
+use crate::assume::assume;
+
+/// Marker for types that can be copied into a raw byte buffer without serializing anything that
+/// would be unsound to observe as bytes: no padding bytes whose contents are unspecified, and no
+/// pointers or references, whose addresses would be meaningless once copied elsewhere (e.g. into a
+/// mapped GPU/DMA buffer).
+/// # Safety
+/// Every bit pattern of `T` must be a valid `T`, `T` must have no padding bytes, and `T` must not
+/// contain any pointer or reference.
+pub unsafe trait Flat: Copy {}
+
+unsafe impl Flat for u8 {}
+unsafe impl Flat for u16 {}
+unsafe impl Flat for u32 {}
+unsafe impl Flat for u64 {}
+unsafe impl Flat for u128 {}
+unsafe impl Flat for usize {}
+unsafe impl Flat for i8 {}
+unsafe impl Flat for i16 {}
+unsafe impl Flat for i32 {}
+unsafe impl Flat for i64 {}
+unsafe impl Flat for i128 {}
+unsafe impl Flat for isize {}
+unsafe impl Flat for f32 {}
+unsafe impl Flat for f64 {}
+
+unsafe impl <T: Flat, const N: usize> Flat for [T; N] {}
+
+/// Where a value ended up after `pack`/`pack_slice` placed it, so callers can chain further
+/// placements starting at `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyRecord {
+    /// Byte offset the value was written at, rounded up from the requested offset to satisfy alignment.
+    pub start: usize,
+    /// Byte offset immediately past the written value.
+    pub end: usize
+}
+
+/// Reasons a placement into a packed buffer can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackError {
+    /// The buffer's guaranteed base alignment is weaker than `align_of::<T>()`, so no offset into it
+    /// can be relied on to satisfy `T`'s alignment.
+    Misaligned,
+    /// The value, once aligned, does not fit within the buffer.
+    OutOfBounds
+}
+
+fn place(len: usize, base_align: usize, offset: usize, align: usize, size: usize) -> Result<CopyRecord, PackError> {
+    if size == 0 {
+        return Ok(CopyRecord { start: offset, end: offset });
+    }
+    if base_align < align {
+        return Err(PackError::Misaligned);
+    }
+    let start = offset.checked_add(align - 1).ok_or(PackError::OutOfBounds)? & !(align - 1);
+    let end = start.checked_add(size).ok_or(PackError::OutOfBounds)?;
+    if end > len {
+        return Err(PackError::OutOfBounds);
+    }
+    Ok(CopyRecord { start, end })
+}
+
+/// Copies `val` into `dst` at the first offset at or after `offset` that satisfies `align_of::<T>()`,
+/// returning where it landed so a following call can start at `record.end`. A zero-sized `T` is
+/// placed at `offset` as given, without rounding and without the alignment check.
+/// # Errors
+/// Returns `Err(PackError::Misaligned)` if `base_align` cannot guarantee `align_of::<T>()` at any
+/// offset, or `Err(PackError::OutOfBounds)` if the aligned value would not fit within `len` bytes.
+/// # Safety
+/// `dst` must be valid for reads and writes of `len` bytes; this is checked neither by this function
+/// nor by the type system, since `dst` may point into memory the allocator does not know about (e.g.
+/// a mapped GPU/DMA buffer).
+pub unsafe fn pack<T: Flat>(dst: *mut u8, len: usize, base_align: usize, offset: usize, val: &T) -> Result<CopyRecord, PackError> {
+    let record = place(len, base_align, offset, align_of::<T>(), size_of::<T>())?;
+    unsafe {
+        ptr::copy_nonoverlapping(val as *const T as *const u8, dst.add(record.start), size_of::<T>());
+    }
+    Ok(record)
+}
+
+/// Copies `val` into `dst` at `offset`, like `pack`, without checking that `base_align` and `len`
+/// actually permit it.
+/// # Safety
+/// The caller must ensure `base_align >= align_of::<T>()` and that the offset rounded up to
+/// `align_of::<T>()`, plus `size_of::<T>()`, does not exceed `len`.
+pub unsafe fn pack_unchecked<T: Flat>(dst: *mut u8, len: usize, base_align: usize, offset: usize, val: &T) -> CopyRecord {
+    if size_of::<T>() == 0 {
+        return CopyRecord { start: offset, end: offset };
+    }
+    unsafe {
+        assume(|| base_align >= align_of::<T>());
+        let start = (offset + align_of::<T>() - 1) & !(align_of::<T>() - 1);
+        let end = start + size_of::<T>();
+        assume(|| end <= len);
+        ptr::copy_nonoverlapping(val as *const T as *const u8, dst.add(start), size_of::<T>());
+        CopyRecord { start, end }
+    }
+}
+
+/// Copies `val` into `dst` at the first offset at or after `offset` that satisfies `align_of::<T>()`,
+/// like `pack`, but for a whole slice copied contiguously. A slice of zero total size is placed at
+/// `offset` as given, without rounding and without the alignment check.
+/// # Errors
+/// Returns `Err(PackError::Misaligned)` if `base_align` cannot guarantee `align_of::<T>()` at any
+/// offset, or `Err(PackError::OutOfBounds)` if the aligned slice would not fit within `len` bytes.
+/// # Safety
+/// `dst` must be valid for reads and writes of `len` bytes; this is checked neither by this function
+/// nor by the type system, since `dst` may point into memory the allocator does not know about (e.g.
+/// a mapped GPU/DMA buffer).
+pub unsafe fn pack_slice<T: Flat>(dst: *mut u8, len: usize, base_align: usize, offset: usize, val: &[T]) -> Result<CopyRecord, PackError> {
+    let size = size_of::<T>().checked_mul(val.len()).ok_or(PackError::OutOfBounds)?;
+    let record = place(len, base_align, offset, align_of::<T>(), size)?;
+    unsafe {
+        ptr::copy_nonoverlapping(val.as_ptr() as *const u8, dst.add(record.start), size);
+    }
+    Ok(record)
+}
+
+/// Copies `val` into `dst` at `offset`, like `pack_slice`, without checking that `base_align` and
+/// `len` actually permit it.
+/// # Safety
+/// The caller must ensure `base_align >= align_of::<T>()` and that the offset rounded up to
+/// `align_of::<T>()`, plus `size_of::<T>() * val.len()`, does not exceed `len`.
+pub unsafe fn pack_slice_unchecked<T: Flat>(dst: *mut u8, len: usize, base_align: usize, offset: usize, val: &[T]) -> CopyRecord {
+    if core::mem::size_of_val(val) == 0 {
+        return CopyRecord { start: offset, end: offset };
+    }
+    unsafe {
+        assume(|| base_align >= align_of::<T>());
+        let size = core::mem::size_of_val(val);
+        let start = (offset + align_of::<T>() - 1) & !(align_of::<T>() - 1);
+        let end = start + size;
+        assume(|| end <= len);
+        ptr::copy_nonoverlapping(val.as_ptr() as *const u8, dst.add(start), size);
+        CopyRecord { start, end }
+    }
+}
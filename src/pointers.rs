@@ -2,6 +2,9 @@ use core::ptr::NonNull;
 use core::marker::PhantomData;
 use core::slice;
 use core::mem::MaybeUninit;
+use core::mem::ManuallyDrop;
+use core::mem::size_of;
+use core::ptr;
 
 /// When working with raw pointers, one must be careful to avoid mutable aliasing. Bind is a zero-sized structure that
 /// makes it easier by ensuring only one pointer can be dereferenced at a time in the same scope.
@@ -101,6 +104,145 @@ impl <T> Pointer<T> for &mut MaybeUninit<T> {
     }
 }
 
+/// An out-reference, `&out T`: a pointer to a slot that may currently hold garbage, paired with the
+/// guarantee that writing through it never reads or runs the destructor of whatever was there before.
+/// This is the missing middle ground between `&mut MaybeUninit<T>` (which forces callers to juggle
+/// `MaybeUninit` themselves) and `&mut T` (which implies a live value that writes must drop first).
+/// # Examples
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use unsafer::pointers::Out;
+/// let mut slot = MaybeUninit::uninit();
+/// let out = Out::from(&mut slot);
+/// let val = out.write(42);
+/// assert_eq!(*val, 42);
+/// ```
+#[repr(transparent)]
+pub struct Out<'out, T: ?Sized> {
+    ptr: NonNull<T>,
+    _ph: PhantomData<&'out mut T>
+}
+
+impl <'out, T> From<&'out mut MaybeUninit<T>> for Out<'out, T> {
+    fn from(slot: &'out mut MaybeUninit<T>) -> Self {
+        Out { ptr: NonNull::from(slot).cast(), _ph: PhantomData }
+    }
+}
+
+impl <'out, T> From<&'out mut ManuallyDrop<T>> for Out<'out, T> {
+    fn from(slot: &'out mut ManuallyDrop<T>) -> Self {
+        Out { ptr: NonNull::from(slot).cast(), _ph: PhantomData }
+    }
+}
+
+// `T: Copy` has no destructor to skip, so an initialized `&mut T` can be coerced directly. Anything
+// else must go through `ManuallyDrop` first so the caller has to acknowledge the old value is leaked.
+impl <'out, T: Copy> From<&'out mut T> for Out<'out, T> {
+    fn from(slot: &'out mut T) -> Self {
+        Out { ptr: NonNull::from(slot), _ph: PhantomData }
+    }
+}
+
+/// Reinterprets a live `&mut T` as `&mut ManuallyDrop<T>`, the step a non-`Copy` `T` must go through
+/// before it can become an `Out<T>` (via `Out::from`), acknowledging that writing through the result
+/// will skip the old value's destructor.
+/// `ManuallyDrop<T>` is `#[repr(transparent)]` over `T`, so this reinterpretation is always sound on
+/// its own; it does not itself run or suppress any destructor.
+pub fn manually_drop_mut<T>(slot: &mut T) -> &mut ManuallyDrop<T> {
+    unsafe { &mut *(slot as *mut T as *mut ManuallyDrop<T>) }
+}
+
+impl <'out, T> Out<'out, T> {
+    /// Builds an `Out` from a raw, already-exclusive pointer.
+    /// # Safety
+    /// `ptr` must be valid for reads and writes and the caller must hold exclusive access to it for `'out`.
+    pub(crate) unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+        Out { ptr, _ph: PhantomData }
+    }
+
+    /// Writes `val` into the slot, without reading or dropping whatever was there, and returns a
+    /// reference to the now-initialized value.
+    pub fn write(self, val: T) -> &'out mut T {
+        unsafe {
+            self.ptr.as_ptr().write(val);
+            &mut *self.ptr.as_ptr()
+        }
+    }
+
+    /// Reinterprets the out-reference as a `MaybeUninit` reference.
+    /// # Safety
+    /// The caller must leave the slot in a state consistent with how it is used afterwards; in
+    /// particular this can be used to de-initialize a slot that other code still treats as live.
+    pub unsafe fn as_mut_uninit(self) -> &'out mut MaybeUninit<T> {
+        unsafe {
+            &mut *(self.ptr.as_ptr() as *mut MaybeUninit<T>)
+        }
+    }
+}
+
+impl <'out, T> From<&'out mut [MaybeUninit<T>]> for Out<'out, [T]> {
+    fn from(slot: &'out mut [MaybeUninit<T>]) -> Self {
+        let ptr = slot as *mut [MaybeUninit<T>] as *mut [T];
+        Out { ptr: unsafe { NonNull::new_unchecked(ptr) }, _ph: PhantomData }
+    }
+}
+
+impl <'out, T: Copy> From<&'out mut [T]> for Out<'out, [T]> {
+    fn from(slot: &'out mut [T]) -> Self {
+        Out { ptr: NonNull::from(slot), _ph: PhantomData }
+    }
+}
+
+impl <'out, T> Out<'out, [T]> {
+    /// Number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.ptr.len()
+    }
+
+    /// Whether the slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Copies `src` into the slice element-wise.
+    /// # Panics
+    /// Panics if `src.len()` does not equal `self.len()`.
+    pub fn copy_from_slice(self, src: &[T]) where T: Copy {
+        assert_eq!(self.len(), src.len(), "source and destination slices have different lengths");
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), self.ptr.as_ptr() as *mut T, src.len());
+        }
+    }
+
+    /// Writes `val` into every element of the slice.
+    pub fn fill(self, val: T) where T: Copy {
+        let base = self.ptr.as_ptr() as *mut T;
+        unsafe {
+            for i in 0..self.len() {
+                base.add(i).write(val);
+            }
+        }
+    }
+
+    /// Splits the out-reference into two at `mid`, so the halves of an uninitialized buffer can be
+    /// filled independently (e.g. by separate calls or threads).
+    /// # Panics
+    /// Panics if `mid > self.len()`.
+    pub fn split_at_out(self, mid: usize) -> (Out<'out, [T]>, Out<'out, [T]>) {
+        let len = self.len();
+        assert!(mid <= len, "mid is out of bounds");
+        let base = self.ptr.as_ptr() as *mut T;
+        unsafe {
+            let left = core::ptr::slice_from_raw_parts_mut(base, mid);
+            let right = core::ptr::slice_from_raw_parts_mut(base.add(mid), len - mid);
+            (
+                Out { ptr: NonNull::new_unchecked(left), _ph: PhantomData },
+                Out { ptr: NonNull::new_unchecked(right), _ph: PhantomData }
+            )
+        }
+    }
+}
+
 impl <T> Bind<T> {
     pub fn new() -> Self {
         Bind { _ph : PhantomData }
@@ -141,4 +283,76 @@ impl <T> Bind<T> {
             slice::from_raw_parts_mut(ptr.as_mut_ptr(), len)
         }
     }
+
+    /// Hands out an `Out` reference bound to `self`, for writing into possibly-uninitialized memory
+    /// without running the previous value's destructor.
+    /// # Safety
+    /// Every invariant for dereferencing a raw pointer applies to Bind.
+    pub unsafe fn get_out(&mut self, mut ptr : impl Pointer<T>) -> Out<'_, T> {
+        unsafe {
+            Out::from_raw(NonNull::new_unchecked(ptr.as_mut_ptr()))
+        }
+    }
+
+    /// Copies `count` elements from `src` to `dst`. Binds no outstanding reference past the call.
+    /// # Safety
+    /// Same as `ptr::copy_nonoverlapping`: both ranges must be valid for `count` reads/writes and must
+    /// not overlap.
+    pub unsafe fn copy_nonoverlapping(&mut self, src : impl Pointer<T>, mut dst : impl Pointer<T>, count : usize) {
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), count);
+        }
+    }
+
+    /// Copies `count` elements from `src` to `dst`. The ranges may overlap.
+    /// # Safety
+    /// Same as `ptr::copy`: both ranges must be valid for `count` reads/writes.
+    pub unsafe fn copy(&mut self, src : impl Pointer<T>, mut dst : impl Pointer<T>, count : usize) {
+        unsafe {
+            ptr::copy(src.as_ptr(), dst.as_mut_ptr(), count);
+        }
+    }
+
+    /// Sets `count` elements starting at `dst` to `val`, byte by byte.
+    /// # Safety
+    /// Same as `ptr::write_bytes`: the range must be valid for `count` writes.
+    pub unsafe fn write_bytes(&mut self, mut dst : impl Pointer<T>, val : u8, count : usize) {
+        unsafe {
+            ptr::write_bytes(dst.as_mut_ptr(), val, count);
+        }
+    }
+
+    /// Swaps the value at `a` with the value at `b`. Unlike `swap_nonoverlapping`, `a` and `b` may
+    /// overlap: the whole value at `a` is snapshotted into a temporary, `b` is then copied over `a`
+    /// (memmove semantics), and the temporary is written to `b`. So `b` always ends up holding the
+    /// value originally at `a`; when the two ranges partially overlap, the overlapping bytes hold
+    /// `a`'s original bytes and only the non-overlapping part of `a` receives `b`'s original bytes
+    /// (the same overlap behavior as `core::ptr::swap`).
+    /// # Safety
+    /// Both `a` and `b` must be valid for a read and a write of `T`.
+    pub unsafe fn swap(&mut self, mut a : impl Pointer<T>, mut b : impl Pointer<T>) {
+        let a = a.as_mut_ptr();
+        let b = b.as_mut_ptr();
+        if a == b || size_of::<T>() == 0 {
+            return;
+        }
+
+        let mut tmp = MaybeUninit::<T>::uninit();
+        unsafe {
+            ptr::copy_nonoverlapping(a, tmp.as_mut_ptr(), 1);
+            ptr::copy(b, a, 1);
+            ptr::copy_nonoverlapping(tmp.as_ptr(), b, 1);
+        }
+    }
+
+    /// Swaps `count` elements between `a` and `b`, assuming the two ranges do not overlap.
+    /// # Safety
+    /// Same as `ptr::swap_nonoverlapping`: both ranges must be valid for `count` reads/writes and must
+    /// not overlap.
+    pub unsafe fn swap_nonoverlapping(&mut self, mut a : impl Pointer<T>, mut b : impl Pointer<T>, count : usize) {
+        unsafe {
+            debug_assert!(a.as_mut_ptr() != b.as_mut_ptr() || count == 0);
+            ptr::swap_nonoverlapping(a.as_mut_ptr(), b.as_mut_ptr(), count);
+        }
+    }
 }
\ No newline at end of file
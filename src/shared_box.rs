@@ -1,7 +1,16 @@
 use core::ptr::NonNull;
 use core::marker::PhantomData;
-use core::mem::forget;
+use core::mem::{forget, MaybeUninit};
 use core::convert::From;
+use core::alloc::Layout;
+use std::alloc::{alloc, handle_alloc_error};
+
+use crate::pointers::Out;
+
+/// An allocation failed because the allocator returned null.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
 /// A Box-like container which manages the memory, but can be dereferenced from multiple locations without MIRI complaining.
 /// # Examples
 /// ```
@@ -60,7 +69,7 @@ impl <T> SharedBox<T> {
     /// # Safety
     /// The user must ensure no active reference to an object in the container still exists.
     #[inline(always)]
-    pub unsafe fn into_box(self) -> Box<T> 
+    pub unsafe fn into_box(self) -> Box<T>
     {
         unsafe {
             let r = Box::from_raw(self.data.as_ptr());
@@ -68,6 +77,56 @@ impl <T> SharedBox<T> {
             r
         }
     }
+
+    /// Allocates space for a `T` and moves `value` into it, without going through `Box::new` first.
+    /// Still takes `value` by value, so it does not by itself avoid a stack copy for large `T`; use
+    /// `try_new_with` for that.
+    pub fn try_new(value: T) -> Result<Self, AllocError> {
+        Self::try_new_with(move |out| { out.write(value); })
+    }
+
+    /// Allocates space for a `T`, uninitialized, and hands `f` an out-reference to write it in place,
+    /// so `T` is never materialized on the stack. If `f` panics, `uninit`'s own `Drop` frees the
+    /// allocation without running any destructor, since nothing has been initialized yet.
+    pub fn try_new_with(f: impl FnOnce(Out<T>)) -> Result<Self, AllocError> {
+        let uninit = Self::try_new_uninit()?;
+        f(unsafe { Out::from_raw(uninit.data.cast()) });
+        Ok(unsafe { uninit.assume_init() })
+    }
+
+    /// Allocates space for a `T`, leaving its contents uninitialized, mirroring `Box::new_uninit`.
+    /// Panics (via the allocator's error handler) if the allocation fails; use `try_new_uninit` to
+    /// handle that instead.
+    pub fn new_uninit() -> SharedBox<MaybeUninit<T>> {
+        match Self::try_new_uninit() {
+            Ok(this) => this,
+            Err(AllocError) => handle_alloc_error(Layout::new::<T>())
+        }
+    }
+
+    /// Fallible version of `new_uninit`.
+    pub fn try_new_uninit() -> Result<SharedBox<MaybeUninit<T>>, AllocError> {
+        let layout = Layout::new::<T>();
+        let data = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            NonNull::new(unsafe { alloc(layout) } as *mut MaybeUninit<T>).ok_or(AllocError)?
+        };
+        Ok(SharedBox { data, _ph: PhantomData })
+    }
+}
+
+impl <T> SharedBox<MaybeUninit<T>> {
+    /// Asserts the contents are initialized and turns this into a `SharedBox<T>`.
+    /// # Safety
+    /// The memory behind the container must have been fully initialized.
+    pub unsafe fn assume_init(self) -> SharedBox<T> {
+        unsafe {
+            let data = self.data.cast::<T>();
+            forget(self);
+            SharedBox { data, _ph: PhantomData }
+        }
+    }
 }
 
 impl <T> Drop for SharedBox<T> {
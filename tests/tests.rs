@@ -1,6 +1,8 @@
 use unsafer::shared_box::SharedBox;
 use unsafer::pointers::*;
 use unsafer::assume::*;
+use unsafer::pack::*;
+use core::mem::MaybeUninit;
 
 struct Increment {
     ctr : *mut i32
@@ -29,6 +31,27 @@ fn shared_test() {
     assert_eq!(*s, 2);
 }
 
+#[test]
+fn shared_box_try_new_test() {
+    let s = SharedBox::try_new(5).unwrap();
+    let s = unsafe { s.into_box() };
+    assert_eq!(*s, 5);
+
+    let s = SharedBox::<Vec<i32>>::try_new_with(|out| {
+        out.write(vec![1, 2, 3]);
+    }).unwrap();
+    let s = unsafe { s.into_box() };
+    assert_eq!(*s, vec![1, 2, 3]);
+
+    let mut s = SharedBox::<i32>::new_uninit();
+    unsafe {
+        *(s.as_ptr() as *mut i32) = 42;
+        let s = s.assume_init();
+        let s = s.into_box();
+        assert_eq!(*s, 42);
+    }
+}
+
 #[allow(unused_unsafe)]
 unsafe fn swap_ptr(a : *mut i32, b : *mut i32) {
     let mut bind = Bind::new();
@@ -39,6 +62,52 @@ unsafe fn swap_ptr(a : *mut i32, b : *mut i32) {
     }
 }
 
+#[test]
+fn out_test() {
+    let mut slot = MaybeUninit::uninit();
+    let out = Out::from(&mut slot);
+    let val = out.write(7);
+    assert_eq!(*val, 7);
+
+    let mut buf = [MaybeUninit::uninit(); 4];
+    let out = Out::from(&mut buf[..]);
+    out.fill(9);
+    let buf = unsafe { core::mem::transmute::<_, [i32; 4]>(buf) };
+    assert_eq!(buf, [9, 9, 9, 9]);
+
+    let mut buf = [MaybeUninit::uninit(); 4];
+    let out = Out::from(&mut buf[..]);
+    out.copy_from_slice(&[1, 2, 3, 4]);
+    let buf = unsafe { core::mem::transmute::<_, [i32; 4]>(buf) };
+    assert_eq!(buf, [1, 2, 3, 4]);
+
+    let mut buf = [MaybeUninit::uninit(); 4];
+    let out = Out::from(&mut buf[..]);
+    let (left, right) = out.split_at_out(1);
+    left.fill(5);
+    right.fill(6);
+    let buf = unsafe { core::mem::transmute::<_, [i32; 4]>(buf) };
+    assert_eq!(buf, [5, 6, 6, 6]);
+
+    let mut slot = MaybeUninit::new(3);
+    let out = Out::from(&mut slot);
+    let uninit = unsafe { out.as_mut_uninit() };
+    *uninit = MaybeUninit::new(11);
+    assert_eq!(unsafe { uninit.assume_init() }, 11);
+
+    // A non-`Copy` value needs an explicit `manually_drop_mut` step to become an `Out`.
+    let mut v = vec![1, 2, 3];
+    let out = Out::from(manually_drop_mut(&mut v));
+    let val = out.write(vec![4, 5, 6]);
+    assert_eq!(*val, vec![4, 5, 6]);
+
+    let mut a = 0;
+    let mut bind = Bind::new();
+    let out = unsafe { bind.get_out(&mut a as *mut i32) };
+    let val = out.write(42);
+    assert_eq!(*val, 42);
+}
+
 #[test]
 fn bind_test() {
     let mut a = 0;
@@ -55,6 +124,123 @@ fn bind_test() {
     assert_eq!(a, 10);
 }
 
+#[test]
+fn bind_bulk_test() {
+    let mut src = [1, 2, 3, 4];
+    let mut dst = [0; 4];
+    let mut bind = Bind::new();
+    unsafe {
+        bind.copy_nonoverlapping(src.as_mut_ptr(), dst.as_mut_ptr(), 4);
+    }
+    assert_eq!(dst, [1, 2, 3, 4]);
+
+    let mut buf = [1, 2, 3, 4, 5];
+    let mut bind = Bind::new();
+    unsafe {
+        // Overlapping shift, exercised through `copy` (memmove semantics).
+        bind.copy(buf.as_ptr(), buf.as_mut_ptr().add(1), 4);
+    }
+    assert_eq!(buf, [1, 1, 2, 3, 4]);
+
+    let mut a = 1;
+    let mut b = 2;
+    let mut bind = Bind::new();
+    unsafe {
+        bind.swap(&mut a as *mut i32, &mut b as *mut i32);
+    }
+    assert_eq!((a, b), (2, 1));
+
+    let mut v = [1, 2, 3, 4];
+    let mut bind = Bind::new();
+    unsafe {
+        let base = v.as_mut_ptr();
+        bind.swap_nonoverlapping(base, base.add(2), 2);
+    }
+    assert_eq!(v, [3, 4, 1, 2]);
+
+    // `T` here is 64 bytes and `a`/`b` overlap by 48 bytes: `b` must end up holding all of `a`'s
+    // original bytes, and only the 16 bytes of `a` outside the overlap receive `b`'s leading bytes.
+    let mut buf: [u8; 80] = core::array::from_fn(|i| i as u8);
+    let mut bind = Bind::new();
+    unsafe {
+        let a = buf.as_mut_ptr() as *mut [u8; 64];
+        let b = buf.as_mut_ptr().add(16) as *mut [u8; 64];
+        bind.swap(a, b);
+    }
+    let expected: [u8; 80] = core::array::from_fn(|i| {
+        if i < 16 {
+            (i + 16) as u8
+        } else {
+            (i - 16) as u8
+        }
+    });
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn pack_test() {
+    let mut buf = [0u8; 16];
+    let record = unsafe { pack(buf.as_mut_ptr(), buf.len(), 8, 1, &1u8).unwrap() };
+    assert_eq!(record, CopyRecord { start: 1, end: 2 });
+
+    // Next u32 must round up past the byte just written.
+    let record = unsafe { pack(buf.as_mut_ptr(), buf.len(), 8, record.end, &0xAABBCCDDu32).unwrap() };
+    assert_eq!(record, CopyRecord { start: 4, end: 8 });
+    assert_eq!(&buf[4..8], &0xAABBCCDDu32.to_ne_bytes());
+
+    // A destination whose guaranteed alignment is weaker than the type's is rejected.
+    let err = unsafe { pack(buf.as_mut_ptr(), buf.len(), 2, 0, &0xAABBCCDDu32).unwrap_err() };
+    assert_eq!(err, PackError::Misaligned);
+
+    // Doesn't fit even once aligned.
+    let err = unsafe { pack(buf.as_mut_ptr(), 4, 8, 1, &0xAABBCCDDu32).unwrap_err() };
+    assert_eq!(err, PackError::OutOfBounds);
+}
+
+#[test]
+fn pack_unchecked_test() {
+    let mut buf = [0u8; 16];
+    let record = unsafe { pack_unchecked(buf.as_mut_ptr(), buf.len(), 8, 1, &1u8) };
+    assert_eq!(record, CopyRecord { start: 1, end: 2 });
+
+    // Next u32 must round up past the byte just written.
+    let record = unsafe { pack_unchecked(buf.as_mut_ptr(), buf.len(), 8, record.end, &0xAABBCCDDu32) };
+    assert_eq!(record, CopyRecord { start: 4, end: 8 });
+    assert_eq!(&buf[4..8], &0xAABBCCDDu32.to_ne_bytes());
+}
+
+#[test]
+fn pack_slice_test() {
+    let mut buf = [0u8; 16];
+    let record = unsafe { pack_slice(buf.as_mut_ptr(), buf.len(), 8, 1, &[1u8, 2u8]).unwrap() };
+    assert_eq!(record, CopyRecord { start: 1, end: 3 });
+
+    // Next slice of u32s must round up past the bytes just written.
+    let vals = [0xAABBCCDDu32, 0x11223344u32];
+    let record = unsafe { pack_slice(buf.as_mut_ptr(), buf.len(), 8, record.end, &vals).unwrap() };
+    assert_eq!(record, CopyRecord { start: 4, end: 12 });
+    assert_eq!(&buf[4..8], &0xAABBCCDDu32.to_ne_bytes());
+    assert_eq!(&buf[8..12], &0x11223344u32.to_ne_bytes());
+
+    // A destination whose guaranteed alignment is weaker than the type's is rejected.
+    let err = unsafe { pack_slice(buf.as_mut_ptr(), buf.len(), 2, 0, &vals).unwrap_err() };
+    assert_eq!(err, PackError::Misaligned);
+
+    // Doesn't fit even once aligned.
+    let err = unsafe { pack_slice(buf.as_mut_ptr(), 4, 8, 1, &vals).unwrap_err() };
+    assert_eq!(err, PackError::OutOfBounds);
+}
+
+#[test]
+fn pack_slice_unchecked_test() {
+    let mut buf = [0u8; 16];
+    let vals = [0xAABBCCDDu32, 0x11223344u32];
+    let record = unsafe { pack_slice_unchecked(buf.as_mut_ptr(), buf.len(), 8, 0, &vals) };
+    assert_eq!(record, CopyRecord { start: 0, end: 8 });
+    assert_eq!(&buf[0..4], &0xAABBCCDDu32.to_ne_bytes());
+    assert_eq!(&buf[4..8], &0x11223344u32.to_ne_bytes());
+}
+
 use std::collections::HashMap;
 
 #[test]
@@ -76,4 +262,24 @@ fn assume_test() {
     assert!(a == 1256);
 }
 
+#[test]
+fn assume_pointer_test() {
+    let x: u32 = 7;
+    let p = &x as *const u32;
+    unsafe {
+        assert_eq!(*assume_aligned(p), 7);
+        assert_eq!(assume_nonnull(p as *mut u32).as_ptr(), p as *mut u32);
+    }
+
+    let r: Result<i32, &str> = Ok(5);
+    unsafe {
+        assert_eq!(r.assume_ok(), 5);
+    }
+
+    let r: Result<i32, &str> = Err("oops");
+    unsafe {
+        assert_eq!(r.assume_err(), "oops");
+    }
+}
+
 